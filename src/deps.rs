@@ -0,0 +1,98 @@
+use crate::Task;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    White,
+    Grey,
+    Black,
+}
+
+/// Returns `true` if adding the edge `from -> to` (i.e. `from` depends on
+/// `to`) would create a cycle in the dependency graph, using a DFS with
+/// white/grey/black marking: a grey (on-stack) node reached during the
+/// search means a cycle.
+pub fn creates_cycle(tasks: &[Task], from: u128, to: u128) -> bool {
+    let mut adjacency: HashMap<u128, Vec<u128>> = tasks
+        .iter()
+        .map(|task| (task.id, task.depends_on.clone()))
+        .collect();
+    adjacency.entry(from).or_default().push(to);
+
+    let mut color: HashMap<u128, Color> =
+        tasks.iter().map(|task| (task.id, Color::White)).collect();
+
+    visit(from, &adjacency, &mut color)
+}
+
+fn visit(node: u128, adjacency: &HashMap<u128, Vec<u128>>, color: &mut HashMap<u128, Color>) -> bool {
+    color.insert(node, Color::Grey);
+    if let Some(neighbors) = adjacency.get(&node) {
+        for &next in neighbors {
+            match color.get(&next).copied().unwrap_or(Color::White) {
+                Color::Grey => return true,
+                Color::White => {
+                    if visit(next, adjacency, color) {
+                        return true;
+                    }
+                }
+                Color::Black => {}
+            }
+        }
+    }
+    color.insert(node, Color::Black);
+    false
+}
+
+/// Returns `true` if `task` has a dependency that either doesn't exist or
+/// isn't done yet.
+pub fn is_blocked(tasks: &[Task], task: &Task) -> bool {
+    task.depends_on.iter().any(|dep_id| {
+        !tasks
+            .iter()
+            .any(|candidate| candidate.id == *dep_id && candidate.done)
+    })
+}
+
+/// Returns the task IDs in a valid execution order (dependencies before
+/// dependents), computed with Kahn's algorithm. Fails if the graph contains
+/// a cycle, which should not happen since `creates_cycle` is checked before
+/// every edge is inserted.
+pub fn topological_order(tasks: &[Task]) -> Result<Vec<u128>, &'static str> {
+    let mut in_degree: HashMap<u128, usize> = tasks.iter().map(|task| (task.id, 0)).collect();
+    let mut dependents: HashMap<u128, Vec<u128>> = tasks.iter().map(|task| (task.id, Vec::new())).collect();
+
+    for task in tasks {
+        for &dep_id in &task.depends_on {
+            *in_degree.entry(task.id).or_insert(0) += 1;
+            dependents.entry(dep_id).or_default().push(task.id);
+        }
+    }
+
+    let mut ready: Vec<u128> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    ready.sort_unstable();
+
+    let mut order = Vec::with_capacity(tasks.len());
+    while let Some(id) = ready.pop() {
+        order.push(id);
+        if let Some(next_ids) = dependents.get(&id) {
+            for &next_id in next_ids {
+                let degree = in_degree.get_mut(&next_id).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(next_id);
+                }
+            }
+        }
+        ready.sort_unstable();
+    }
+
+    if order.len() != tasks.len() {
+        return Err("Dependency graph contains a cycle");
+    }
+    Ok(order)
+}
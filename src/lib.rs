@@ -1,7 +1,13 @@
 use chrono::naive::NaiveDate;
+use chrono::{Datelike, Duration, Local, Months, Weekday};
+use serde::{Deserialize, Serialize};
 use std::io::{self, Write};
 use std::process;
 
+mod deps;
+mod render;
+mod storage;
+
 /// Gets input from the user with the given prompt.
 ///
 /// # Arguments
@@ -38,7 +44,11 @@ pub fn get_input(prompt: &str) -> String {
 /// - `description`: The description of the task.
 /// - `done`: Indicates whether the task is done or not.
 /// - `priority`: The priority of the task.
-/// - `due_date`: The due date of the task.
+/// - `due_date`: The hard deadline for the task.
+/// - `when`: The date the user plans to start working on the task, if set.
+/// - `reminder`: The date to be reminded about the task, if set.
+/// - `tags`: Free-form labels used to categorize the task (e.g. `work`, `home`).
+/// - `depends_on`: IDs of tasks that must be done before this one can start.
 ///
 /// # Example
 ///
@@ -52,9 +62,14 @@ pub fn get_input(prompt: &str) -> String {
 ///     description: String::from("Complete the final tasks for the project"),
 ///     done: false,
 ///     priority: Priority::High,
-///     due_date: NaiveDate::from_ymd(2022, 12, 31),
+///     due_date: NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+///     when: None,
+///     reminder: None,
+///     tags: Vec::new(),
+///     depends_on: Vec::new(),
 /// };
 /// ```
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: u128,
     pub title: String,
@@ -62,6 +77,117 @@ pub struct Task {
     pub done: bool,
     pub priority: Priority,
     pub due_date: NaiveDate,
+    pub when: Option<NaiveDate>,
+    pub reminder: Option<NaiveDate>,
+    pub tags: Vec<String>,
+    pub depends_on: Vec<u128>,
+}
+
+impl Task {
+    /// Serializes this task to a compact, `todo.txt`-style line:
+    /// `<id>. [x] "<title>" due:<date> prio:<priority> +tag1 +tag2`.
+    /// Only the fields the format covers are written; `description`,
+    /// `when`, `reminder`, and `depends_on` don't round-trip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::naive::NaiveDate;
+    /// use todo_app::{Task, Priority};
+    ///
+    /// let task = Task {
+    ///     id: 1,
+    ///     title: String::from("Finish project"),
+    ///     description: String::new(),
+    ///     done: false,
+    ///     priority: Priority::High,
+    ///     due_date: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+    ///     when: None,
+    ///     reminder: None,
+    ///     tags: vec![String::from("work")],
+    ///     depends_on: Vec::new(),
+    /// };
+    /// assert_eq!(task.to_line(), "1. [ ] \"Finish project\" due:2024-12-31 prio:high +work");
+    /// ```
+    pub fn to_line(&self) -> String {
+        let mut line = format!(
+            "{}. [{}] \"{}\" due:{} prio:{}",
+            self.id,
+            if self.done { "x" } else { " " },
+            self.title,
+            self.due_date.format("%Y-%m-%d"),
+            format!("{:?}", self.priority).to_lowercase(),
+        );
+        for tag in &self.tags {
+            line.push_str(&format!(" +{}", tag));
+        }
+        line
+    }
+
+    /// Parses a line written by [`Task::to_line`]. Tolerates a missing
+    /// `due:` (defaulting to today), a missing `prio:` (defaulting to
+    /// `Priority::Low`), and no `+tag` tokens at all, returning a
+    /// descriptive `Err` instead of panicking on malformed input.
+    pub fn from_line(line: &str) -> Result<Task, String> {
+        let line = line.trim();
+        let (id_part, rest) = line
+            .split_once(". ")
+            .ok_or_else(|| format!("Missing ID in line: {}", line))?;
+        let id: u128 = id_part
+            .parse()
+            .map_err(|_| format!("Invalid ID in line: {}", line))?;
+
+        let rest = rest
+            .trim_start()
+            .strip_prefix('[')
+            .ok_or_else(|| format!("Missing done marker in line: {}", line))?;
+        let (marker, rest) = rest
+            .split_once(']')
+            .ok_or_else(|| format!("Unterminated done marker in line: {}", line))?;
+        let done = match marker.trim() {
+            "x" | "X" => true,
+            "" => false,
+            other => return Err(format!("Invalid done marker '{}' in line: {}", other, line)),
+        };
+
+        let rest = rest
+            .trim_start()
+            .strip_prefix('"')
+            .ok_or_else(|| format!("Missing title in line: {}", line))?;
+        let (title, rest) = rest
+            .split_once('"')
+            .ok_or_else(|| format!("Unterminated title in line: {}", line))?;
+
+        let mut due_date = Local::now().date_naive();
+        let mut priority = Priority::Low;
+        let mut tags = Vec::new();
+        for token in rest.split_whitespace() {
+            if let Some(value) = token.strip_prefix("due:") {
+                due_date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|_| format!("Invalid due date '{}' in line: {}", value, line))?;
+            } else if let Some(value) = token.strip_prefix("prio:") {
+                priority = validate_priority(value)
+                    .map_err(|_| format!("Invalid priority '{}' in line: {}", value, line))?;
+            } else if let Some(tag) = token.strip_prefix('+') {
+                tags.push(tag.to_string());
+            } else {
+                return Err(format!("Unrecognized token '{}' in line: {}", token, line));
+            }
+        }
+
+        Ok(Task {
+            id,
+            title: title.to_string(),
+            description: String::new(),
+            done,
+            priority,
+            due_date,
+            when: None,
+            reminder: None,
+            tags,
+            depends_on: Vec::new(),
+        })
+    }
 }
 
 /// Represents the priority of a task.
@@ -75,7 +201,7 @@ pub struct Task {
 /// use todo_app::Priority;
 /// let priority = Priority::High;
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Priority {
     Low,
     Medium,
@@ -98,6 +224,10 @@ pub enum Priority {
 ///     done: false,
 ///     priority: Priority::High,
 ///     due_date: NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+///     when: None,
+///     reminder: None,
+///     tags: Vec::new(),
+///     depends_on: Vec::new(),
 /// };
 /// let command = Command::AddTask(task);
 /// ```
@@ -106,7 +236,90 @@ pub enum Command {
     RemoveTask(u128),
     MarkAsDone(u128),
     ChangePriority(u128, Priority),
-    ListTasks,
+    /// Lists tasks matching the given `Filter`. The first `bool` forces the
+    /// uncolored `--plain` table rendering; the second reorders the
+    /// result topologically (dependencies before dependents) for a
+    /// `--next` view.
+    ListTasks(Filter, bool, bool),
+    /// Applies a `TaskUpdate` to the task with the given ID in place.
+    UpdateTask(u128, TaskUpdate),
+    /// Makes the first task depend on the second, rejecting the edge if the
+    /// dependency doesn't exist or it would create a cycle.
+    AddDependency(u128, u128),
+    /// Removes a dependency edge, if present.
+    RemoveDependency(u128, u128),
+}
+
+/// A set of in-place edits to apply to a task via [`Command::UpdateTask`].
+/// Every field is optional so a CLI invocation only needs to mention the
+/// fields it actually wants to change. `add_tags` is appended to the task's
+/// existing tags and deduplicated, rather than replacing them.
+#[derive(Debug, Clone, Default)]
+pub struct TaskUpdate {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub due_date: Option<NaiveDate>,
+    pub add_tags: Vec<String>,
+}
+
+/// Which tasks to include in a listing, based on their done status.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TodoStatus {
+    #[default]
+    Active,
+    Done,
+    All,
+}
+
+/// Narrows down the tasks returned by [`filter_tasks`]. The default filter
+/// (`TodoStatus::Active`, no other constraints) matches every task that
+/// isn't done yet.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub status: TodoStatus,
+    pub priority: Option<Priority>,
+    pub due_before: Option<NaiveDate>,
+    pub due_after: Option<NaiveDate>,
+    pub tag: Option<String>,
+    pub blocked_only: bool,
+}
+
+/// Returns the tasks that match the given filter, preserving their order.
+///
+/// # Example
+///
+/// ```
+/// use todo_app::{filter_tasks, Filter, TodoStatus};
+///
+/// let tasks = Vec::new();
+/// let filter = Filter { status: TodoStatus::Done, ..Filter::default() };
+/// let done_tasks = filter_tasks(&tasks, &filter);
+/// ```
+pub fn filter_tasks<'a>(tasks: &'a [Task], filter: &Filter) -> Vec<&'a Task> {
+    tasks
+        .iter()
+        .filter(|task| match filter.status {
+            TodoStatus::Active => !task.done,
+            TodoStatus::Done => task.done,
+            TodoStatus::All => true,
+        })
+        .filter(|task| match &filter.priority {
+            Some(priority) => task.priority == *priority,
+            None => true,
+        })
+        .filter(|task| {
+            filter.due_after.is_none_or(|after| task.due_date >= after)
+                && filter
+                    .due_before
+                    .is_none_or(|before| task.due_date <= before)
+        })
+        .filter(|task| match &filter.tag {
+            Some(tag) => task.tags.contains(tag),
+            None => true,
+        })
+        .filter(|task| !filter.blocked_only || deps::is_blocked(tasks, task))
+        .collect()
 }
 
 /// Executes the given command with the given list of tasks.
@@ -130,6 +343,10 @@ pub enum Command {
 ///     done: false,
 ///     priority: Priority::High,
 ///     due_date: NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+///     when: None,
+///     reminder: None,
+///     tags: Vec::new(),
+///     depends_on: Vec::new(),
 /// };
 /// let mut tasks: Vec<Task> = Vec::new();
 /// execute(Command::AddTask(task), &mut tasks);
@@ -145,6 +362,12 @@ pub fn execute(command: Command, task_list: &mut Vec<Task>) {
             let index: Option<usize> = task_list.iter().position(|task| task.id == id);
             if let Some(index) = index {
                 task_list.swap_remove(index);
+                // Scrub the removed id from every other task's depends_on so it
+                // doesn't linger as a dangling dependency that topological_order
+                // can never resolve.
+                for task in task_list.iter_mut() {
+                    task.depends_on.retain(|&dep_id| dep_id != id);
+                }
             } else {
                 println!("Task not found");
             }
@@ -167,17 +390,112 @@ pub fn execute(command: Command, task_list: &mut Vec<Task>) {
                 println!("Task not found");
             }
         }
-        Command::ListTasks => {
-            // List all tasks
-            for task in task_list {
-                println!("Task ID: {}", task.id);
-                println!("Title: {}", task.title);
-                println!("Description: {}", task.description);
-                println!("Done: {}", task.done);
-                println!("Priority: {:?}", task.priority);
-                println!("Due Date: {}", task.due_date);
+        Command::ListTasks(filter, plain, topological) => {
+            // List the tasks matching the filter, colorized unless `plain` is set
+            let filtered = filter_tasks(task_list, &filter);
+            if topological {
+                match deps::topological_order(task_list) {
+                    Ok(order) => {
+                        let ordered: Vec<&Task> = order
+                            .iter()
+                            .filter_map(|id| filtered.iter().find(|task| task.id == *id).copied())
+                            .collect();
+                        render::render_tasks(&ordered, plain);
+                    }
+                    Err(err) => println!("{}", err),
+                }
+            } else {
+                render::render_tasks(&filtered, plain);
+            }
+        }
+        Command::AddDependency(id, dep_id) => {
+            // Add a dependency edge from `id` to `dep_id`, rejecting it if either
+            // task is missing or it would introduce a cycle
+            if id == dep_id {
+                println!("A task cannot depend on itself");
+            } else if !task_list.iter().any(|task| task.id == dep_id) {
+                println!("Dependency task not found");
+            } else if !task_list.iter().any(|task| task.id == id) {
+                println!("Task not found");
+            } else if deps::creates_cycle(task_list, id, dep_id) {
+                println!("That dependency would create a cycle");
+            } else {
+                let task = task_list.iter_mut().find(|task| task.id == id).unwrap();
+                if !task.depends_on.contains(&dep_id) {
+                    task.depends_on.push(dep_id);
+                }
             }
         }
+        Command::RemoveDependency(id, dep_id) => {
+            let task = task_list.iter_mut().find(|task| task.id == id);
+            if let Some(task) = task {
+                task.depends_on.retain(|&existing| existing != dep_id);
+            } else {
+                println!("Task not found");
+            }
+        }
+        Command::UpdateTask(id, update) => {
+            // Apply the given edits to the task with the given ID in place
+            let task = task_list.iter_mut().find(|task| task.id == id);
+            if let Some(task) = task {
+                if let Some(title) = update.title {
+                    task.title = title;
+                }
+                if let Some(description) = update.description {
+                    task.description = description;
+                }
+                if let Some(priority) = update.priority {
+                    task.priority = priority;
+                }
+                if let Some(due_date) = update.due_date {
+                    task.due_date = due_date;
+                }
+                for tag in update.add_tags {
+                    if !task.tags.contains(&tag) {
+                        task.tags.push(tag);
+                    }
+                }
+            } else {
+                println!("Task not found");
+            }
+        }
+    }
+}
+
+/// Resolves a `remove`/`done`/`priority` argument to a task ID, accepting
+/// either a raw task ID or a 1-based position in `tasks` (its stored
+/// order, the same order `list --all` prints them in; a default `list`
+/// hides done tasks, so its positions can differ). If the argument
+/// matches an existing task ID and also lands on a *different* task's
+/// position, the two interpretations disagree and `Err` is returned rather
+/// than silently picking one.
+///
+/// # Example
+///
+/// ```
+/// use todo_app::{resolve_target, Task};
+///
+/// let tasks: Vec<Task> = Vec::new();
+/// assert!(resolve_target(&tasks, "1").is_err());
+/// ```
+pub fn resolve_target(tasks: &[Task], arg: &str) -> Result<u128, String> {
+    let number: u128 = arg.parse().map_err(|_| format!("No task at position {}", arg))?;
+
+    let by_id = tasks.iter().any(|task| task.id == number).then_some(number);
+    let by_position = usize::try_from(number)
+        .ok()
+        .filter(|&index| index >= 1 && index <= tasks.len())
+        .and_then(|index| tasks.get(index - 1))
+        .map(|task| task.id);
+
+    match (by_id, by_position) {
+        (Some(id), Some(position_id)) if id == position_id => Ok(id),
+        (Some(_), Some(_)) => Err(format!(
+            "ambiguous target {}: matches both task id {} and position {}",
+            number, number, number
+        )),
+        (Some(id), None) | (None, Some(id)) => Ok(id),
+        (None, None) => Err(format!("No task at position {}", arg)),
     }
 }
 
@@ -208,6 +526,9 @@ pub fn validate_priority(priority: &str) -> Result<Priority, &'static str> {
 }
 
 /// Handles the date string and converts it to a NaiveDate.
+/// Accepts an ISO date (`2024-12-22`), the app's own `%d-%m-%Y` format
+/// (`22-12-2024`), or a natural-language phrase such as `today`, `tomorrow`,
+/// `next friday`, or `in 3 days`, tried in that order.
 ///
 /// # Arguments
 ///
@@ -222,9 +543,74 @@ pub fn validate_priority(priority: &str) -> Result<Priority, &'static str> {
 /// ```
 /// use todo_app::handle_date;
 /// let date = handle_date("22-12-2024").unwrap();
+/// let date = handle_date("tomorrow").unwrap();
 /// ```
 pub fn handle_date(date: &str) -> Result<NaiveDate, &'static str> {
-    NaiveDate::parse_from_str(date, "%d-%m-%Y").map_err(|_| "Invalid date format")
+    if let Ok(parsed) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return Ok(parsed);
+    }
+    if let Ok(parsed) = NaiveDate::parse_from_str(date, "%d-%m-%Y") {
+        return Ok(parsed);
+    }
+    parse_relative_date(date)
+}
+
+/// Parses natural-language relative dates such as `today`, `next friday`,
+/// or `in 3 weeks`, resolved against the current local date.
+fn parse_relative_date(date: &str) -> Result<NaiveDate, &'static str> {
+    let today = Local::now().date_naive();
+    let phrase = date.trim().to_lowercase();
+
+    match phrase.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + Duration::days(1)),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+
+    let weekday_name = phrase.strip_prefix("next ").unwrap_or(&phrase);
+    if let Some(weekday) = parse_weekday(weekday_name) {
+        return Ok(next_weekday(today, weekday));
+    }
+
+    let tokens: Vec<&str> = phrase.split_whitespace().collect();
+    if let ["in", amount, unit] = tokens[..] {
+        let amount: u32 = amount.parse().map_err(|_| "Invalid date format")?;
+        return match unit.trim_end_matches('s') {
+            "day" => Ok(today + Duration::days(amount as i64)),
+            "week" => Ok(today + Duration::weeks(amount as i64)),
+            "month" => today
+                .checked_add_months(Months::new(amount))
+                .ok_or("Invalid date format"),
+            _ => Err("Invalid date format"),
+        };
+    }
+
+    Err("Invalid date format")
+}
+
+/// Maps a weekday name to its `chrono::Weekday`.
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns the next occurrence of `target` strictly after `from`.
+fn next_weekday(from: NaiveDate, target: Weekday) -> NaiveDate {
+    let mut days_ahead =
+        target.num_days_from_monday() as i64 - from.weekday().num_days_from_monday() as i64;
+    if days_ahead <= 0 {
+        days_ahead += 7;
+    }
+    from + Duration::days(days_ahead)
 }
 
 /// Represents the configuration of the todo app.
@@ -274,35 +660,123 @@ impl Config {
     }
 }
 
-/// Runs the todo app with the given configuration and list of tasks.
-/// The function executes the command specified in the configuration on the list of tasks.
+/// Prompts the user for an optional date, returning `None` if they leave
+/// the input blank and printing a warning (without aborting) if it doesn't parse.
+fn prompt_optional_date(prompt: &str) -> Option<NaiveDate> {
+    let input = get_input(prompt);
+    if input.is_empty() {
+        return None;
+    }
+    match handle_date(&input) {
+        Ok(date) => Some(date),
+        Err(err) => {
+            eprintln!("{}", err);
+            None
+        }
+    }
+}
+
+/// Prompts the user for a comma-separated list of tags, trimming whitespace
+/// and dropping empty entries. Returns an empty `Vec` if they leave the
+/// input blank.
+fn prompt_tags(prompt: &str) -> Vec<String> {
+    get_input(prompt)
+        .split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Parses `--title`/`--description`/`--priority`/`--due`/`--tag` flags for
+/// the `update`/`edit` command into a `TaskUpdate`. `--tag` may be repeated
+/// to append more than one tag.
+fn parse_task_update(args: &[String]) -> TaskUpdate {
+    let mut update = TaskUpdate::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => {
+                update.title = Some(
+                    args.next()
+                        .unwrap_or_else(|| {
+                            eprintln!("--title requires a value");
+                            process::exit(1);
+                        })
+                        .clone(),
+                );
+            }
+            "--description" => {
+                update.description = Some(
+                    args.next()
+                        .unwrap_or_else(|| {
+                            eprintln!("--description requires a value");
+                            process::exit(1);
+                        })
+                        .clone(),
+                );
+            }
+            "--priority" => {
+                let value = args.next().unwrap_or_else(|| {
+                    eprintln!("--priority requires a value");
+                    process::exit(1);
+                });
+                update.priority = Some(validate_priority(value).unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }));
+            }
+            "--due" => {
+                let value = args.next().unwrap_or_else(|| {
+                    eprintln!("--due requires a value");
+                    process::exit(1);
+                });
+                update.due_date = Some(handle_date(value).unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }));
+            }
+            "--tag" => {
+                let value = args.next().unwrap_or_else(|| {
+                    eprintln!("--tag requires a value");
+                    process::exit(1);
+                });
+                update.add_tags.push(value.clone());
+            }
+            other => {
+                eprintln!("Unknown argument for update: {}", other);
+                process::exit(1);
+            }
+        }
+    }
+    update
+}
+
+/// Runs the todo app with the given configuration.
+/// The function loads the persisted task list, executes the command specified in the
+/// configuration against it, and saves the list back to disk if the command mutated it.
 /// The function handles the add, remove, done, priority, and list commands.
 ///
 /// # Arguments
 ///
 /// - `config`: The configuration of the todo app.
-/// - `tasks`: The list of tasks to perform the action on.
 ///
 /// # Example
 ///
-/// ```
-/// use todo_app::{run, Config, Task};
+/// ```no_run
+/// use todo_app::{run, Config};
 /// let config = Config {
-///    command: String::from("done"),
-///  arguments: vec![String::from("1")],
+///    command: String::from("list"),
+///  arguments: vec![],
 /// };
-/// let tasks: Vec<Task> = Vec::new();
-/// run(config, tasks);
+/// run(config);
 /// ```
-pub fn run(config: Config, mut tasks: Vec<Task>) {
+pub fn run(config: Config) {
+    let mut tasks: Vec<Task> = storage::load_tasks();
+    let mut next_id: u128 = storage::next_id();
+
     // Handle the command
     match config.command.as_str() {
         "add" => {
-            // TODO: Implement the add command
-            // - Getting the task details from the user
-            // - Creating a new task
-            // - Adding the task to the list of tasks or saving it to a file
-            let id: u128 = tasks.len() as u128 + 1;
             let title: String = get_input("Task title: ");
             let description: String = get_input("Task description: ");
             let done: bool = false;
@@ -318,20 +792,28 @@ pub fn run(config: Config, mut tasks: Vec<Task>) {
                     eprintln!("{}", err);
                     process::exit(1);
                 });
+            let when = prompt_optional_date("When do you plan to start? (optional, Enter to skip): ");
+            let reminder = prompt_optional_date("Remind you on? (optional, Enter to skip): ");
+            let tags = prompt_tags("Tags (comma separated, optional): ");
             let task: Task = Task {
-                id: id,
+                id: next_id,
                 title: title,
                 description: description,
                 done: done,
                 priority: priority,
                 due_date: due_date,
+                when: when,
+                reminder: reminder,
+                tags: tags,
+                depends_on: Vec::new(),
             };
+            next_id += 1;
             let command = Command::AddTask(task);
             execute(command, &mut tasks);
-            execute(Command::ListTasks, &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(Command::ListTasks(Filter { status: TodoStatus::All, ..Filter::default() }, false, false), &mut tasks);
         }
         "remove" => {
-            // TODO: Implement the remove command (Argument: task ID)
             if config.arguments.len() < 1 {
                 eprintln!("Task ID is required for the remove command");
                 process::exit(1);
@@ -339,23 +821,19 @@ pub fn run(config: Config, mut tasks: Vec<Task>) {
                 eprintln!("Too many arguments for the remove command");
                 process::exit(1);
             }
-            // - Validating the task ID
-            let task_id: u128 = config
-                .arguments
-                .get(0)
-                .unwrap()
-                .parse::<u128>()
-                .unwrap_or_else(|err| {
-                    eprintln!("Invalid task ID: {}", err);
-                    process::exit(1);
-                });
+            // - Resolving the argument to a task ID (by ID or list position)
+            let arg = config.arguments.get(0).unwrap();
+            let task_id: u128 = resolve_target(&tasks, arg).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
             // - Removing the task from the list of tasks
             let command: Command = Command::RemoveTask(task_id);
             execute(command, &mut tasks);
-            execute(Command::ListTasks, &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(Command::ListTasks(Filter { status: TodoStatus::All, ..Filter::default() }, false, false), &mut tasks);
         }
         "done" => {
-            // TODO: Implement the done command (Argument: task ID)
             if config.arguments.len() < 1 {
                 eprintln!("Task ID is required for the done command");
                 process::exit(1);
@@ -363,23 +841,19 @@ pub fn run(config: Config, mut tasks: Vec<Task>) {
                 eprintln!("Too many arguments for the done command");
                 process::exit(1);
             }
-            // - Validating the task ID
-            let task_id: u128 = config
-                .arguments
-                .get(0)
-                .unwrap()
-                .parse::<u128>()
-                .unwrap_or_else(|err| {
-                    eprintln!("Invalid task ID: {}", err);
-                    process::exit(1);
-                });
+            // - Resolving the argument to a task ID (by ID or list position)
+            let arg = config.arguments.get(0).unwrap();
+            let task_id: u128 = resolve_target(&tasks, arg).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
             // - Changing the done status of the task
             let command: Command = Command::MarkAsDone(task_id);
             execute(command, &mut tasks);
-            execute(Command::ListTasks, &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(Command::ListTasks(Filter { status: TodoStatus::All, ..Filter::default() }, false, false), &mut tasks);
         }
         "priority" => {
-            // TODO: Implement the priority command (Arguments: task ID, priority)
             if config.arguments.len() < 2 {
                 eprintln!("Task ID and priority are required for the priority command");
                 process::exit(1);
@@ -387,16 +861,12 @@ pub fn run(config: Config, mut tasks: Vec<Task>) {
                 eprintln!("Too many arguments for the priority command");
                 process::exit(1);
             }
-            // - Validating the task ID
-            let task_id: u128 = config
-                .arguments
-                .get(0)
-                .unwrap()
-                .parse::<u128>()
-                .unwrap_or_else(|err| {
-                    eprintln!("Invalid task ID: {}", err);
-                    process::exit(1);
-                });
+            // - Resolving the argument to a task ID (by ID or list position)
+            let arg = config.arguments.get(0).unwrap();
+            let task_id: u128 = resolve_target(&tasks, arg).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
             // - Validating the priority
             let priority: &String = config.arguments.get(1).unwrap_or_else(|| {
                 eprintln!("Priority is required for the priority command");
@@ -410,12 +880,201 @@ pub fn run(config: Config, mut tasks: Vec<Task>) {
             // - Changing the priority of the task
             let command: Command = Command::ChangePriority(task_id, priority);
             execute(command, &mut tasks);
-            execute(Command::ListTasks, &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(Command::ListTasks(Filter { status: TodoStatus::All, ..Filter::default() }, false, false), &mut tasks);
         }
         "list" => {
-            // TODO: Implement the list command
-            let command: Command = Command::ListTasks;
+            let mut filter = Filter::default();
+            let mut plain = false;
+            let mut topological = false;
+            let mut args = config.arguments.iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--done" => filter.status = TodoStatus::Done,
+                    "--all" => filter.status = TodoStatus::All,
+                    "--plain" => plain = true,
+                    "--priority" => {
+                        let value = args.next().unwrap_or_else(|| {
+                            eprintln!("--priority requires a value");
+                            process::exit(1);
+                        });
+                        filter.priority = Some(validate_priority(value).unwrap_or_else(|err| {
+                            eprintln!("{}", err);
+                            process::exit(1);
+                        }));
+                    }
+                    "--due-before" => {
+                        let value = args.next().unwrap_or_else(|| {
+                            eprintln!("--due-before requires a value");
+                            process::exit(1);
+                        });
+                        filter.due_before = Some(handle_date(value).unwrap_or_else(|err| {
+                            eprintln!("{}", err);
+                            process::exit(1);
+                        }));
+                    }
+                    "--due-after" => {
+                        let value = args.next().unwrap_or_else(|| {
+                            eprintln!("--due-after requires a value");
+                            process::exit(1);
+                        });
+                        filter.due_after = Some(handle_date(value).unwrap_or_else(|err| {
+                            eprintln!("{}", err);
+                            process::exit(1);
+                        }));
+                    }
+                    "--tag" => {
+                        let value = args.next().unwrap_or_else(|| {
+                            eprintln!("--tag requires a value");
+                            process::exit(1);
+                        });
+                        filter.tag = Some(value.clone());
+                    }
+                    "--blocked" => filter.blocked_only = true,
+                    "--next" => topological = true,
+                    other => {
+                        eprintln!("Unknown argument for list: {}", other);
+                        process::exit(1);
+                    }
+                }
+            }
+            let command: Command = Command::ListTasks(filter, plain, topological);
+            execute(command, &mut tasks);
+        }
+        "update" | "edit" => {
+            if config.arguments.is_empty() {
+                eprintln!("Task ID is required for the update command");
+                process::exit(1);
+            }
+            let task_id: u128 = config.arguments[0].parse().unwrap_or_else(|err| {
+                eprintln!("Invalid task ID: {}", err);
+                process::exit(1);
+            });
+            let update = parse_task_update(&config.arguments[1..]);
+            let command: Command = Command::UpdateTask(task_id, update);
             execute(command, &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(
+                Command::ListTasks(
+                    Filter {
+                        status: TodoStatus::All,
+                        ..Filter::default()
+                    },
+                    false,
+                    false,
+                ),
+                &mut tasks,
+            );
+        }
+        "depend" => {
+            if config.arguments.len() != 2 {
+                eprintln!("Usage: depend <task-id> <depends-on-id>");
+                process::exit(1);
+            }
+            let task_id: u128 = config.arguments[0].parse().unwrap_or_else(|err| {
+                eprintln!("Invalid task ID: {}", err);
+                process::exit(1);
+            });
+            let dep_id: u128 = config.arguments[1].parse().unwrap_or_else(|err| {
+                eprintln!("Invalid dependency ID: {}", err);
+                process::exit(1);
+            });
+            execute(Command::AddDependency(task_id, dep_id), &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(
+                Command::ListTasks(
+                    Filter {
+                        status: TodoStatus::All,
+                        ..Filter::default()
+                    },
+                    false,
+                    false,
+                ),
+                &mut tasks,
+            );
+        }
+        "undepend" => {
+            if config.arguments.len() != 2 {
+                eprintln!("Usage: undepend <task-id> <depends-on-id>");
+                process::exit(1);
+            }
+            let task_id: u128 = config.arguments[0].parse().unwrap_or_else(|err| {
+                eprintln!("Invalid task ID: {}", err);
+                process::exit(1);
+            });
+            let dep_id: u128 = config.arguments[1].parse().unwrap_or_else(|err| {
+                eprintln!("Invalid dependency ID: {}", err);
+                process::exit(1);
+            });
+            execute(Command::RemoveDependency(task_id, dep_id), &mut tasks);
+            storage::save_tasks(&tasks, next_id);
+            execute(
+                Command::ListTasks(
+                    Filter {
+                        status: TodoStatus::All,
+                        ..Filter::default()
+                    },
+                    false,
+                    false,
+                ),
+                &mut tasks,
+            );
+        }
+        "export" => {
+            if config.arguments.len() != 1 {
+                eprintln!("Usage: export <file>");
+                process::exit(1);
+            }
+            let path = &config.arguments[0];
+            let contents = tasks
+                .iter()
+                .map(Task::to_line)
+                .collect::<Vec<String>>()
+                .join("\n");
+            if let Err(err) = std::fs::write(path, contents) {
+                eprintln!("Failed to write {}: {}", path, err);
+                process::exit(1);
+            }
+        }
+        "import" => {
+            if config.arguments.len() != 1 {
+                eprintln!("Usage: import <file>");
+                process::exit(1);
+            }
+            let path = &config.arguments[0];
+            let contents = std::fs::read_to_string(path).unwrap_or_else(|err| {
+                eprintln!("Failed to read {}: {}", path, err);
+                process::exit(1);
+            });
+            for (line_no, line) in contents.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match Task::from_line(line) {
+                    Ok(mut task) => {
+                        // Renumber imported tasks whose ID collides with one already
+                        // loaded, so import never clobbers or shadows an existing task.
+                        if tasks.iter().any(|existing| existing.id == task.id) {
+                            task.id = next_id;
+                        }
+                        next_id = next_id.max(task.id + 1);
+                        execute(Command::AddTask(task), &mut tasks);
+                    }
+                    Err(err) => eprintln!("Skipping line {}: {}", line_no + 1, err),
+                }
+            }
+            storage::save_tasks(&tasks, next_id);
+            execute(
+                Command::ListTasks(
+                    Filter {
+                        status: TodoStatus::All,
+                        ..Filter::default()
+                    },
+                    false,
+                    false,
+                ),
+                &mut tasks,
+            );
         }
         _ => {
             eprintln!("Invalid command: {}", config.command);
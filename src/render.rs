@@ -0,0 +1,86 @@
+use crate::{Priority, Task};
+use chrono::Local;
+use prettytable::{color, format, Attr, Cell, Row, Table};
+use std::io::IsTerminal;
+
+/// Renders the given tasks as a table to stdout.
+/// Colorizes priority, done, and overdue cells when stdout is a terminal and
+/// `plain` is `false`; otherwise falls back to an uncolored table so piped
+/// output stays clean.
+pub fn render_tasks(tasks: &[&Task], plain: bool) {
+    if plain || !std::io::stdout().is_terminal() {
+        render_plain(tasks);
+    } else {
+        render_colored(tasks);
+    }
+}
+
+fn table_with_titles() -> Table {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(Row::new(vec![
+        Cell::new("ID"),
+        Cell::new("Title"),
+        Cell::new("Priority"),
+        Cell::new("Due"),
+        Cell::new("Done"),
+    ]));
+    table
+}
+
+fn render_plain(tasks: &[&Task]) {
+    let mut table = table_with_titles();
+    for task in tasks {
+        table.add_row(Row::new(vec![
+            Cell::new(&task.id.to_string()),
+            Cell::new(&task.title),
+            Cell::new(&format!("{:?}", task.priority)),
+            Cell::new(&task.due_date.to_string()),
+            Cell::new(if task.done { "yes" } else { "no" }),
+        ]));
+    }
+    table.printstd();
+}
+
+fn render_colored(tasks: &[&Task]) {
+    let today = Local::now().date_naive();
+    let mut table = table_with_titles();
+    for task in tasks {
+        let priority_color = match task.priority {
+            Priority::High => color::RED,
+            Priority::Medium => color::YELLOW,
+            Priority::Low => color::GREEN,
+        };
+        let overdue = !task.done && task.due_date < today;
+
+        let mut id_cell = Cell::new(&task.id.to_string());
+        let mut title_cell = Cell::new(&task.title);
+        let mut priority_cell =
+            Cell::new(&format!("{:?}", task.priority)).with_style(Attr::ForegroundColor(priority_color));
+        let mut due_cell = Cell::new(&task.due_date.to_string());
+        let mut done_cell = Cell::new(if task.done { "yes" } else { "no" });
+
+        if overdue {
+            due_cell = due_cell
+                .with_style(Attr::ForegroundColor(color::RED))
+                .with_style(Attr::Bold);
+        }
+
+        if task.done {
+            id_cell = id_cell.with_style(Attr::Dim);
+            title_cell = title_cell.with_style(Attr::Dim);
+            priority_cell = priority_cell.with_style(Attr::Dim);
+            due_cell = due_cell.with_style(Attr::Dim);
+            done_cell = done_cell.with_style(Attr::Dim);
+        }
+
+        table.add_row(Row::new(vec![
+            id_cell,
+            title_cell,
+            priority_cell,
+            due_cell,
+            done_cell,
+        ]));
+    }
+    table.printstd();
+}
@@ -0,0 +1,64 @@
+use crate::Task;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// On-disk representation of the task list, including the monotonic ID
+/// counter so IDs are never reused after a task is removed.
+#[derive(Serialize, Deserialize, Default)]
+struct TaskData {
+    next_id: u128,
+    tasks: Vec<Task>,
+}
+
+/// Resolves the path to the JSON file the task list is persisted to
+/// (`~/.todo_app/tasks.json`).
+fn data_file() -> PathBuf {
+    let mut path = dirs::home_dir().unwrap_or_default();
+    path.push(".todo_app");
+    path.push("tasks.json");
+    path
+}
+
+/// Reads the task data file, falling back to an empty data set if it is
+/// missing or cannot be parsed.
+fn load() -> TaskData {
+    let path = data_file();
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the given task data to the data file, creating its parent
+/// directory if necessary.
+fn save(data: &TaskData) {
+    let path = data_file();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(data) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Loads the persisted list of tasks.
+pub fn load_tasks() -> Vec<Task> {
+    load().tasks
+}
+
+/// Loads the persisted ID counter, defaulting to `1` for a fresh data file.
+pub fn next_id() -> u128 {
+    match load().next_id {
+        0 => 1,
+        id => id,
+    }
+}
+
+/// Persists the given tasks and the next ID counter to the data file.
+pub fn save_tasks(tasks: &[Task], next_id: u128) {
+    save(&TaskData {
+        next_id,
+        tasks: tasks.to_vec(),
+    });
+}